@@ -1,9 +1,109 @@
 //use bevy::input::mouse::*;
 use bevy::prelude::*;
+use bevy::render::camera::Viewport;
 
 #[derive(Component)]
 pub struct Player;
 
+/// Size of the `Player` sprite, used for the drag hit-test in [`respond_to_player_drag`]
+const PLAYER_SIZE: Vec2 = Vec2::new(64.0, 32.0);
+
+/// Fixed logical viewport size given to every camera so that
+/// `Camera::viewport_to_world_2d` works without a real `Window` entity
+const VIEWPORT_SIZE: UVec2 = UVec2::new(1280, 720);
+
+/// Marks the `Player` entity while it is being dragged by the mouse
+#[derive(Component)]
+pub struct Dragged;
+
+/// The world-space position of the cursor, as last computed through the active camera
+#[derive(Resource, Default)]
+pub struct CursorWorldPosition(pub Option<Vec2>);
+
+/// Marks the entity the camera follows while in [`CameraMode::Follow`]
+#[derive(Component)]
+pub struct CameraTarget;
+
+/// Whether the camera is freely panned by the user or follows a [`CameraTarget`]
+#[derive(Resource, Default, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CameraMode {
+    #[default]
+    Free,
+    Follow,
+}
+
+/// Smoothing factor used to ease the camera towards its followed target
+const FOCUS_CAMERA_SMOOTHING: f32 = 0.1;
+
+/// Minimum allowed `OrthographicProjection::size`
+const MIN_ZOOM: f32 = 0.2;
+
+/// Maximum allowed `OrthographicProjection::size`
+const MAX_ZOOM: f32 = 5.0;
+
+/// Units per second the camera pans when a keyboard key is held
+const KEYBOARD_PAN_SPEED: f32 = 200.0;
+
+/// Runtime-configurable input sensitivity, read by the mouse-driven camera systems
+/// instead of their previously hard-coded literals
+#[derive(Resource)]
+pub struct CameraSettings {
+    pub move_sensitivity: f32,
+    pub rotate_speed: f32,
+    pub zoom_speed: f32,
+    pub lerp_factor: f32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        CameraSettings {
+            move_sensitivity: 1.0 / 20.0,
+            rotate_speed: 0.1,
+            zoom_speed: 1.0 / 10.0,
+            lerp_factor: 0.5,
+        }
+    }
+}
+
+/// What the mouse wheel currently controls, cycled at runtime by [`cycle_scroll_target`]
+#[derive(Resource, Default, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ScrollType {
+    #[default]
+    Zoom,
+    MoveSpeed,
+    RotateSpeed,
+}
+
+/// The cameras spawned by [`add_camera`], in cycling order.
+///
+/// Index 0 is always the user-controlled camera; pressing the cycle key
+/// advances through the rest and wraps back to it.
+#[derive(Resource, Default)]
+pub struct CameraCycle {
+    pub cameras: Vec<Entity>,
+    pub active_index: usize,
+}
+
+/// Tracks the zoom level the camera is smoothly moving towards
+#[derive(Resource)]
+pub struct CameraZoom {
+    pub target_size: f32,
+    pub min: f32,
+    pub max: f32,
+    pub smoothing: f32,
+}
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        CameraZoom {
+            target_size: 1.0,
+            min: MIN_ZOOM,
+            max: MAX_ZOOM,
+            smoothing: 0.5,
+        }
+    }
+}
+
 pub fn create_app() -> App {
     let mut app = App::new();
 
@@ -13,18 +113,82 @@ pub fn create_app() -> App {
         app.add_plugins(bevy::input::InputPlugin);
     }
 
+    app.init_resource::<CameraZoom>();
+    app.init_resource::<CameraMode>();
+    app.init_resource::<CameraCycle>();
+    app.init_resource::<CursorWorldPosition>();
+    app.init_resource::<CameraSettings>();
+    app.init_resource::<ScrollType>();
+
     app.add_systems(Startup, (add_camera, add_player));
-    app.add_systems(Update, (respond_to_mouse_button_press, respond_to_mouse_move, respond_to_mouse_wheel_turn));
+    app.add_systems(
+        Update,
+        (
+            track_cursor_world_position,
+            respond_to_player_drag,
+            respond_to_mouse_button_press,
+            respond_to_mouse_move,
+            cycle_scroll_target,
+            respond_to_mouse_wheel_turn,
+            smooth_camera_zoom,
+            respond_to_keyboard_pan,
+            toggle_camera_mode,
+            cycle_active_camera,
+        )
+            .chain(),
+    );
+    app.add_systems(PostUpdate, focus_camera);
 
     // Do not do update, as this will disallow to do more steps
     // app.update(); //Don't!
     app
 }
 
-fn add_camera(mut commands: Commands) {
-    commands.spawn(
-        Camera2dBundle::default()
-    );
+fn fixed_viewport() -> Viewport {
+    Viewport {
+        physical_position: UVec2::ZERO,
+        physical_size: VIEWPORT_SIZE,
+        ..default()
+    }
+}
+
+fn add_camera(mut commands: Commands, mut cycle: ResMut<CameraCycle>) {
+    let user_camera = commands
+        .spawn(Camera2dBundle {
+            camera: Camera {
+                viewport: Some(fixed_viewport()),
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    // Additional fixed viewpoints the user can cycle to with `cycle_active_camera`
+    let fixed_camera_1 = commands
+        .spawn(Camera2dBundle {
+            camera: Camera {
+                is_active: false,
+                viewport: Some(fixed_viewport()),
+                ..default()
+            },
+            transform: Transform::from_xyz(500.0, 0.0, 0.0),
+            ..default()
+        })
+        .id();
+    let fixed_camera_2 = commands
+        .spawn(Camera2dBundle {
+            camera: Camera {
+                is_active: false,
+                viewport: Some(fixed_viewport()),
+                ..default()
+            },
+            transform: Transform::from_xyz(-500.0, 0.0, 0.0),
+            ..default()
+        })
+        .id();
+
+    cycle.cameras = vec![user_camera, fixed_camera_1, fixed_camera_2];
+    cycle.active_index = 0;
 }
 
 fn add_player(mut commands: Commands) {
@@ -37,46 +201,233 @@ fn add_player(mut commands: Commands) {
             ..default()
         },
         Player,
+        CameraTarget,
     ));
 }
 
+fn track_cursor_world_position(
+    mut cursor_world_position: ResMut<CursorWorldPosition>,
+    mut cursor_moved_events: EventReader<bevy::window::CursorMoved>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    for event in cursor_moved_events.read() {
+        let Some((camera, camera_transform)) =
+            camera_query.iter().find(|(camera, _)| camera.is_active)
+        else {
+            continue;
+        };
+        cursor_world_position.0 = camera.viewport_to_world_2d(camera_transform, event.position);
+    }
+}
+
+fn respond_to_player_drag(
+    mut commands: Commands,
+    cursor_world_position: Res<CursorWorldPosition>,
+    input: Res<ButtonInput<MouseButton>>,
+    mut player_query: Query<(Entity, &mut Transform, Option<&Dragged>), With<Player>>,
+) {
+    let Ok((player_entity, mut transform, dragged)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    if input.just_pressed(MouseButton::Left) && dragged.is_none() {
+        if let Some(cursor_position) = cursor_world_position.0 {
+            let half_size = PLAYER_SIZE / 2.0;
+            let player_position = transform.translation.xy();
+            let within_x = (cursor_position.x - player_position.x).abs() <= half_size.x;
+            let within_y = (cursor_position.y - player_position.y).abs() <= half_size.y;
+            if within_x && within_y {
+                commands.entity(player_entity).insert(Dragged);
+            }
+        }
+        return;
+    }
+
+    if dragged.is_none() {
+        return;
+    }
+
+    if input.just_released(MouseButton::Left) {
+        commands.entity(player_entity).remove::<Dragged>();
+        return;
+    }
+
+    if let Some(cursor_position) = cursor_world_position.0 {
+        transform.translation.x = cursor_position.x;
+        transform.translation.y = cursor_position.y;
+    }
+}
+
 fn respond_to_mouse_button_press(
-    mut query: Query<&mut Transform, With<Camera>>,
+    mut query: Query<(&mut Transform, &Camera)>,
     input: Res<ButtonInput<MouseButton>>,
+    dragged_query: Query<(), With<Dragged>>,
+    settings: Res<CameraSettings>,
 ) {
-    let mut transform = query.single_mut();
+    if !dragged_query.is_empty() {
+        return;
+    }
+    let Some((mut transform, _)) = query.iter_mut().find(|(_, camera)| camera.is_active) else {
+        return;
+    };
     if input.pressed(MouseButton::Left) {
-        transform.rotate_z(0.1);
+        transform.rotate_z(settings.rotate_speed);
     }
     if input.pressed(MouseButton::Right) {
-        transform.rotate_z(-0.1);
+        transform.rotate_z(-settings.rotate_speed);
     }
 }
 
 fn respond_to_mouse_move(
-    mut query: Query<&mut Transform, With<Camera>>,
+    mut query: Query<(&mut Transform, &Camera)>,
     mut mouse_motion_event: EventReader<bevy::input::mouse::MouseMotion>,
+    settings: Res<CameraSettings>,
 ) {
     for event in mouse_motion_event.read() {
-        let mut transform = query.single_mut();
-        transform.translation.x += event.delta.x / 20.0;
-        transform.translation.y -= event.delta.y / 20.0;
+        let Some((mut transform, _)) = query.iter_mut().find(|(_, camera)| camera.is_active)
+        else {
+            continue;
+        };
+        transform.translation.x += event.delta.x * settings.move_sensitivity;
+        transform.translation.y -= event.delta.y * settings.move_sensitivity;
     }
 }
 
 fn respond_to_mouse_wheel_turn(
-    mut query: Query<&mut OrthographicProjection, With<Camera>>,
+    scroll_target: Res<ScrollType>,
+    mut settings: ResMut<CameraSettings>,
+    mut zoom: ResMut<CameraZoom>,
     mut mouse_wheel_event: EventReader<bevy::input::mouse::MouseWheel>,
 ) {
     for event in mouse_wheel_event.read() {
-        let mut projection = query.single_mut();
-        // Do something
-        projection.size *= 1.0 + ((event.x + event.y) / 10.0);
+        let delta = event.x + event.y;
+        match *scroll_target {
+            ScrollType::Zoom => {
+                let new_target = zoom.target_size * (1.0 + (delta * settings.zoom_speed));
+                zoom.target_size = new_target.clamp(zoom.min, zoom.max);
+            }
+            ScrollType::MoveSpeed => {
+                settings.move_sensitivity =
+                    (settings.move_sensitivity * (1.0 + (delta * settings.zoom_speed))).max(0.0);
+            }
+            ScrollType::RotateSpeed => {
+                settings.rotate_speed =
+                    (settings.rotate_speed * (1.0 + (delta * settings.zoom_speed))).max(0.0);
+            }
+        }
+    }
+}
+
+fn cycle_scroll_target(
+    mut scroll_target: ResMut<ScrollType>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyT) {
+        *scroll_target = match *scroll_target {
+            ScrollType::Zoom => ScrollType::MoveSpeed,
+            ScrollType::MoveSpeed => ScrollType::RotateSpeed,
+            ScrollType::RotateSpeed => ScrollType::Zoom,
+        };
     }
 }
 
+fn smooth_camera_zoom(
+    mut query: Query<(&mut OrthographicProjection, &Camera)>,
+    zoom: Res<CameraZoom>,
+) {
+    let Some((mut projection, _)) = query.iter_mut().find(|(_, camera)| camera.is_active) else {
+        return;
+    };
+    projection.size += (zoom.target_size - projection.size) * zoom.smoothing;
+}
+
+fn respond_to_keyboard_pan(
+    mut query: Query<(&mut Transform, &Camera)>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<CameraSettings>,
+    mut velocity: Local<Vec2>,
+) {
+    let mut direction = Vec2::ZERO;
+    if keyboard_input.pressed(KeyCode::KeyW) || keyboard_input.pressed(KeyCode::ArrowUp) {
+        direction.y += 1.0;
+    }
+    if keyboard_input.pressed(KeyCode::KeyS) || keyboard_input.pressed(KeyCode::ArrowDown) {
+        direction.y -= 1.0;
+    }
+    if keyboard_input.pressed(KeyCode::KeyA) || keyboard_input.pressed(KeyCode::ArrowLeft) {
+        direction.x -= 1.0;
+    }
+    if keyboard_input.pressed(KeyCode::KeyD) || keyboard_input.pressed(KeyCode::ArrowRight) {
+        direction.x += 1.0;
+    }
+
+    let target_velocity = direction.normalize_or_zero() * KEYBOARD_PAN_SPEED;
+    *velocity = velocity.lerp(target_velocity, settings.lerp_factor);
+
+    if *velocity != Vec2::ZERO {
+        if let Some((mut transform, _)) = query.iter_mut().find(|(_, camera)| camera.is_active) {
+            transform.translation.x += velocity.x;
+            transform.translation.y += velocity.y;
+        }
+    }
+}
+
+fn cycle_active_camera(
+    mut cycle: ResMut<CameraCycle>,
+    mut camera_query: Query<&mut Camera>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyC) || cycle.cameras.is_empty() {
+        return;
+    }
+
+    if let Ok(mut camera) = camera_query.get_mut(cycle.cameras[cycle.active_index]) {
+        camera.is_active = false;
+    }
+
+    cycle.active_index = (cycle.active_index + 1) % cycle.cameras.len();
+
+    if let Ok(mut camera) = camera_query.get_mut(cycle.cameras[cycle.active_index]) {
+        camera.is_active = true;
+    }
+}
+
+fn toggle_camera_mode(mut mode: ResMut<CameraMode>, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.just_pressed(KeyCode::KeyF) {
+        *mode = match *mode {
+            CameraMode::Free => CameraMode::Follow,
+            CameraMode::Follow => CameraMode::Free,
+        };
+    }
+}
+
+fn focus_camera(
+    mode: Res<CameraMode>,
+    mut camera_query: Query<(&mut Transform, &Camera)>,
+    target_query: Query<&Transform, (With<CameraTarget>, Without<Camera>)>,
+) {
+    if *mode != CameraMode::Follow {
+        return;
+    }
+    let Some((mut camera_transform, _)) =
+        camera_query.iter_mut().find(|(_, camera)| camera.is_active)
+    else {
+        return;
+    };
+    let target_translation = target_query.single().translation;
+    camera_transform.translation = camera_transform
+        .translation
+        .lerp(target_translation, FOCUS_CAMERA_SMOOTHING);
+}
+
 #[cfg(test)]
 fn count_n_cameras(app: &mut App) -> usize {
+    let mut query = app.world_mut().query::<&Camera>();
+    query.iter(app.world()).filter(|camera| camera.is_active).count()
+}
+
+#[cfg(test)]
+fn count_n_all_cameras(app: &mut App) -> usize {
     let mut query = app.world_mut().query::<&Camera>();
     query.iter(app.world()).len()
 }
@@ -90,7 +441,10 @@ fn count_n_players(app: &mut App) -> usize {
 #[cfg(test)]
 fn get_camera_position(app: &mut App) -> Vec2 {
     let mut query = app.world_mut().query::<(&Transform, &Camera)>();
-    let (transform, _) = query.single(app.world());
+    let (transform, _) = query
+        .iter(app.world())
+        .find(|(_, camera)| camera.is_active)
+        .expect("there should always be exactly one active camera");
     transform.translation.xy()
 }
 
@@ -104,7 +458,10 @@ fn get_player_position(app: &mut App) -> Vec2 {
 #[cfg(test)]
 fn get_camera_scale(app: &mut App) -> f32 {
     let mut query = app.world_mut().query::<(&OrthographicProjection, &Camera)>();
-    let (projection, _) = query.single(app.world());
+    let (projection, _) = query
+        .iter(app.world())
+        .find(|(_, camera)| camera.is_active)
+        .expect("there should always be exactly one active camera");
     projection.size
 }
 #[cfg(test)]
@@ -117,7 +474,10 @@ fn get_player_size(app: &mut App) -> Vec2 {
 #[cfg(test)]
 fn get_camera_rotation(app: &mut App) -> f32 {
     let mut query = app.world_mut().query::<(&Transform, &Camera)>();
-    let (transform, _) = query.single(app.world());
+    let (transform, _) = query
+        .iter(app.world())
+        .find(|(_, camera)| camera.is_active)
+        .expect("there should always be exactly one active camera");
     transform.rotation.z
 }
 
@@ -257,4 +617,268 @@ mod tests {
         // Moved now
         assert_ne!(get_camera_scale(&mut app), 1.0);
     }
+
+    #[test]
+    fn test_camera_zoom_stays_within_clamp_range() {
+        let mut app = create_app();
+        app.update();
+
+        // Scroll out far enough to hit the upper clamp many times over
+        for _ in 0..100 {
+            app.world_mut().send_event(bevy::input::mouse::MouseWheel {
+                unit: bevy::input::mouse::MouseScrollUnit::Line,
+                x: 100.0,
+                y: 100.0,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+        }
+
+        let zoom = app.world().resource::<CameraZoom>();
+        assert_eq!(zoom.target_size, zoom.max);
+        assert!(get_camera_scale(&mut app) <= zoom.max);
+        assert!(get_camera_scale(&mut app) >= zoom.min);
+    }
+
+    #[test]
+    fn test_camera_responds_to_keyboard_pan_up() {
+        let mut app = create_app();
+        app.update();
+        assert_eq!(get_camera_position(&mut app), Vec2::new(0.0, 0.0));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyW);
+
+        app.update();
+
+        assert!(get_camera_position(&mut app).y > 0.0);
+    }
+
+    #[test]
+    fn test_camera_responds_to_keyboard_pan_with_arrow_keys() {
+        let mut app = create_app();
+        app.update();
+        assert_eq!(get_camera_position(&mut app), Vec2::new(0.0, 0.0));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowRight);
+
+        app.update();
+
+        assert!(get_camera_position(&mut app).x > 0.0);
+    }
+
+    #[test]
+    fn test_camera_mode_starts_as_free() {
+        let mut app = create_app();
+        app.update();
+        assert_eq!(*app.world().resource::<CameraMode>(), CameraMode::Free);
+    }
+
+    #[test]
+    fn test_pressing_f_toggles_camera_mode() {
+        let mut app = create_app();
+        app.update();
+        assert_eq!(*app.world().resource::<CameraMode>(), CameraMode::Free);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyF);
+        app.update();
+
+        assert_eq!(*app.world().resource::<CameraMode>(), CameraMode::Follow);
+    }
+
+    #[test]
+    fn test_camera_follows_player_in_follow_mode() {
+        let mut app = create_app();
+        app.update();
+
+        *app.world_mut().resource_mut::<CameraMode>() = CameraMode::Follow;
+
+        let mut player_query = app.world_mut().query::<(&mut Transform, &Player)>();
+        let (mut player_transform, _) = player_query.single_mut(app.world_mut());
+        player_transform.translation = Vec3::new(500.0, 500.0, 0.0);
+
+        for _ in 0..200 {
+            app.update();
+        }
+
+        let camera_position = get_camera_position(&mut app);
+        assert!((camera_position.x - 500.0).abs() < 1.0);
+        assert!((camera_position.y - 500.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_create_app_spawns_multiple_cameras_but_only_one_active() {
+        let mut app = create_app();
+        app.update();
+        assert_eq!(count_n_all_cameras(&mut app), 3);
+        assert_eq!(count_n_cameras(&mut app), 1);
+    }
+
+    #[test]
+    fn test_pressing_c_cycles_the_active_camera() {
+        let mut app = create_app();
+        app.update();
+
+        let position_on_user_camera = get_camera_position(&mut app);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyC);
+        app.update();
+
+        // Still exactly one active camera, but no longer the user camera
+        assert_eq!(count_n_cameras(&mut app), 1);
+        let position_on_fixed_camera = get_camera_position(&mut app);
+        assert_ne!(position_on_user_camera, position_on_fixed_camera);
+
+        // Mouse input now drives the newly active (fixed) camera
+        app.world_mut().send_event(bevy::input::mouse::MouseMotion {
+            delta: Vec2::new(100.0, 100.0),
+        });
+        app.update();
+        assert_ne!(get_camera_position(&mut app), position_on_fixed_camera);
+    }
+
+    #[test]
+    fn test_dragging_the_player_follows_the_cursor() {
+        let mut app = create_app();
+        app.update();
+        assert_eq!(get_player_position(&mut app), Vec2::new(0.0, 0.0));
+
+        // Move the cursor onto the player (viewport center maps to world origin)
+        app.world_mut().send_event(bevy::window::CursorMoved {
+            window: Entity::PLACEHOLDER,
+            position: Vec2::new(640.0, 360.0),
+            delta: None,
+        });
+        app.update();
+
+        // Press the left mouse button while hovering the player
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        // Drag the cursor away from the player
+        app.world_mut().send_event(bevy::window::CursorMoved {
+            window: Entity::PLACEHOLDER,
+            position: Vec2::new(740.0, 360.0),
+            delta: None,
+        });
+        app.update();
+
+        assert!(get_player_position(&mut app).x > 0.0);
+    }
+
+    #[test]
+    fn test_camera_does_not_rotate_while_player_is_dragged() {
+        let mut app = create_app();
+        app.update();
+
+        app.world_mut().send_event(bevy::window::CursorMoved {
+            window: Entity::PLACEHOLDER,
+            position: Vec2::new(640.0, 360.0),
+            delta: None,
+        });
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        // The left mouse button is being used to drag, not to rotate
+        assert_eq!(get_camera_rotation(&mut app), 0.0);
+    }
+
+    #[test]
+    fn test_mouse_move_delta_scales_with_move_sensitivity() {
+        let mut app = create_app();
+        app.update();
+
+        app.world_mut().resource_mut::<CameraSettings>().move_sensitivity = 1.0;
+
+        app.world_mut().send_event(bevy::input::mouse::MouseMotion {
+            delta: Vec2::new(10.0, 0.0),
+        });
+        app.update();
+
+        // With a sensitivity of 1.0 the full delta is applied
+        assert_eq!(get_camera_position(&mut app).x, 10.0);
+    }
+
+    #[test]
+    fn test_scroll_target_starts_at_zoom() {
+        let mut app = create_app();
+        app.update();
+        assert_eq!(*app.world().resource::<ScrollType>(), ScrollType::Zoom);
+    }
+
+    #[test]
+    fn test_pressing_t_cycles_the_scroll_target() {
+        let mut app = create_app();
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyT);
+        app.update();
+
+        assert_eq!(*app.world().resource::<ScrollType>(), ScrollType::MoveSpeed);
+    }
+
+    #[test]
+    fn test_scrolling_while_targeting_move_speed_changes_move_sensitivity() {
+        let mut app = create_app();
+        app.update();
+
+        *app.world_mut().resource_mut::<ScrollType>() = ScrollType::MoveSpeed;
+        let initial_sensitivity = app.world().resource::<CameraSettings>().move_sensitivity;
+
+        app.world_mut().send_event(bevy::input::mouse::MouseWheel {
+            unit: bevy::input::mouse::MouseScrollUnit::Line,
+            x: 10.0,
+            y: 10.0,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+
+        let new_sensitivity = app.world().resource::<CameraSettings>().move_sensitivity;
+        assert_ne!(new_sensitivity, initial_sensitivity);
+
+        // Zoom must be unaffected, since the wheel is targeting move speed
+        assert_eq!(get_camera_scale(&mut app), 1.0);
+    }
+
+    #[test]
+    fn test_scrolling_while_targeting_rotate_speed_changes_rotate_speed() {
+        let mut app = create_app();
+        app.update();
+
+        *app.world_mut().resource_mut::<ScrollType>() = ScrollType::RotateSpeed;
+        let initial_rotate_speed = app.world().resource::<CameraSettings>().rotate_speed;
+
+        app.world_mut().send_event(bevy::input::mouse::MouseWheel {
+            unit: bevy::input::mouse::MouseScrollUnit::Line,
+            x: 10.0,
+            y: 10.0,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+
+        let new_rotate_speed = app.world().resource::<CameraSettings>().rotate_speed;
+        assert_ne!(new_rotate_speed, initial_rotate_speed);
+
+        // Zoom and move sensitivity must be unaffected, since the wheel is targeting rotate speed
+        assert_eq!(get_camera_scale(&mut app), 1.0);
+        assert_eq!(
+            app.world().resource::<CameraSettings>().move_sensitivity,
+            1.0 / 20.0
+        );
+    }
 }